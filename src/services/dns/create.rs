@@ -0,0 +1,306 @@
+use super::record_data::RecordData;
+use crate::core::Endpoint;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+/// 添加解析记录结果
+#[derive(Debug, Deserialize)]
+pub struct CreateRecordResult {
+    /// 记录ID
+    #[serde(rename = "RecordId")]
+    pub record_id: u64,
+
+    /// 唯一请求ID
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+/// 添加解析记录响应
+#[derive(Debug, Deserialize)]
+pub struct CreateRecordResponse {
+    #[serde(rename = "Response")]
+    pub response: CreateRecordResult,
+}
+
+/// 请求参数结构体 - 添加解析记录
+///
+/// 记录类型与记录值由 [`RecordData`] 统一承载，`RecordType`、`Value`、`MX`
+/// 等字段在序列化时自动从枚举派生，避免类型与取值不一致。
+pub struct CreateRecord<'a> {
+    /// 域名
+    pub domain: &'a str,
+
+    /// 主机记录，如 www，默认为 @
+    pub sub_domain: Option<&'a str>,
+
+    /// 记录数据
+    pub record_data: RecordData,
+
+    /// 记录线路，默认为“默认”
+    pub record_line: Option<&'a str>,
+
+    /// 域名ID。参数 DomainId 优先级比参数 Domain 高
+    pub domain_id: Option<u64>,
+
+    /// 记录线路ID，如果传RecordLineId，系统会忽略RecordLine参数
+    pub record_line_id: Option<&'a str>,
+
+    /// 权重信息，用于负载均衡记录
+    pub weight: Option<u32>,
+
+    /// TTL，范围1-604800，不同等级域名最小值不同
+    pub ttl: Option<u32>,
+
+    /// 记录初始状态，ENABLE 或 DISABLE，默认 ENABLE
+    pub status: Option<&'a str>,
+
+    /// 备注
+    pub remark: Option<&'a str>,
+}
+
+impl<'a> CreateRecord<'a> {
+    /// 创建新的请求实例
+    pub fn new(domain: &'a str, record_data: RecordData) -> Self {
+        Self {
+            domain,
+            sub_domain: None,
+            record_data,
+            record_line: None,
+            domain_id: None,
+            record_line_id: None,
+            weight: None,
+            ttl: None,
+            status: None,
+            remark: None,
+        }
+    }
+
+    /// 设置主机记录
+    pub fn with_sub_domain(mut self, sub_domain: &'a str) -> Self {
+        self.sub_domain = Some(sub_domain);
+        self
+    }
+
+    /// 设置记录线路
+    pub fn with_record_line(mut self, record_line: &'a str) -> Self {
+        self.record_line = Some(record_line);
+        self
+    }
+
+    /// 设置域名ID
+    pub fn with_domain_id(mut self, domain_id: u64) -> Self {
+        self.domain_id = Some(domain_id);
+        self
+    }
+
+    /// 设置记录线路ID
+    pub fn with_record_line_id(mut self, record_line_id: &'a str) -> Self {
+        self.record_line_id = Some(record_line_id);
+        self
+    }
+
+    /// 设置权重
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// 设置TTL
+    pub fn with_ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// 设置记录初始状态
+    pub fn with_status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// 设置备注
+    pub fn with_remark(mut self, remark: &'a str) -> Self {
+        self.remark = Some(remark);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateRecord<'a> {
+    type Output = CreateRecordResponse;
+
+    fn service(&self) -> Cow<'static, str> {
+        Cow::Borrowed("dnspod")
+    }
+
+    fn action(&self) -> Cow<'static, str> {
+        Cow::Borrowed("CreateRecord")
+    }
+
+    fn version(&self) -> Cow<'static, str> {
+        Cow::Borrowed("2021-03-23")
+    }
+
+    fn region(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn payload(&self) -> Value {
+        let mut payload = json!({
+            "Domain": self.domain,
+            "SubDomain": self.sub_domain.unwrap_or("@"),
+            "RecordType": self.record_data.record_type(),
+            "RecordLine": self.record_line.unwrap_or("默认"),
+            "Value": self.record_data.value(),
+        });
+
+        if let Some(mx) = self.record_data.mx() {
+            payload["MX"] = json!(mx);
+        }
+        if let Some(domain_id) = self.domain_id {
+            payload["DomainId"] = json!(domain_id);
+        }
+        if let Some(record_line_id) = self.record_line_id {
+            payload["RecordLineId"] = json!(record_line_id);
+        }
+        if let Some(weight) = self.weight {
+            payload["Weight"] = json!(weight);
+        }
+        if let Some(ttl) = self.ttl {
+            payload["TTL"] = json!(ttl);
+        }
+        if let Some(status) = self.status {
+            payload["Status"] = json!(status);
+        }
+        if let Some(remark) = self.remark {
+            payload["Remark"] = json!(remark);
+        }
+
+        payload
+    }
+}
+
+/// 请求参数结构体 - 添加 TXT 解析记录
+///
+/// [`CreateRecord`] 的便捷封装，固定记录类型为 TXT，保留原有的 TXT 专用入口。
+pub struct CreateTXTRecord<'a> {
+    inner: CreateRecord<'a>,
+}
+
+impl<'a> CreateTXTRecord<'a> {
+    /// 创建新的请求实例
+    pub fn new(domain: &'a str, value: impl Into<String>) -> Self {
+        Self {
+            inner: CreateRecord::new(domain, RecordData::TXT(value.into())),
+        }
+    }
+
+    /// 设置主机记录
+    pub fn with_sub_domain(mut self, sub_domain: &'a str) -> Self {
+        self.inner = self.inner.with_sub_domain(sub_domain);
+        self
+    }
+
+    /// 设置TTL
+    pub fn with_ttl(mut self, ttl: u32) -> Self {
+        self.inner = self.inner.with_ttl(ttl);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateTXTRecord<'a> {
+    type Output = CreateRecordResponse;
+
+    fn service(&self) -> Cow<'static, str> {
+        self.inner.service()
+    }
+
+    fn action(&self) -> Cow<'static, str> {
+        self.inner.action()
+    }
+
+    fn version(&self) -> Cow<'static, str> {
+        self.inner.version()
+    }
+
+    fn region(&self) -> Option<Cow<'_, str>> {
+        self.inner.region()
+    }
+
+    fn payload(&self) -> Value {
+        self.inner.payload()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_create_record_payload_a() {
+        let request = CreateRecord::new("dnspod.cn", RecordData::A(Ipv4Addr::new(1, 1, 1, 1)))
+            .with_sub_domain("www")
+            .with_ttl(600);
+
+        let payload = request.payload();
+        assert_eq!(payload["Domain"], json!("dnspod.cn"));
+        assert_eq!(payload["SubDomain"], json!("www"));
+        assert_eq!(payload["RecordType"], json!("A"));
+        assert_eq!(payload["RecordLine"], json!("默认"));
+        assert_eq!(payload["Value"], json!("1.1.1.1"));
+        assert_eq!(payload["TTL"], json!(600));
+        assert!(payload.get("MX").is_none());
+    }
+
+    #[test]
+    fn test_create_record_payload_mx() {
+        let request = CreateRecord::new(
+            "dnspod.cn",
+            RecordData::MX {
+                preference: 10,
+                exchange: "mail.dnspod.cn".into(),
+            },
+        );
+
+        let payload = request.payload();
+        assert_eq!(payload["RecordType"], json!("MX"));
+        assert_eq!(payload["Value"], json!("mail.dnspod.cn"));
+        assert_eq!(payload["MX"], json!(10));
+        assert_eq!(payload["SubDomain"], json!("@"));
+    }
+
+    #[test]
+    fn test_deserialize_create_record_response() {
+        let json = r#"{
+            "Response": {
+                "RecordId": 162,
+                "RequestId": "req-abc"
+            }
+        }"#;
+
+        let response: CreateRecordResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.response.record_id, 162);
+        assert_eq!(response.response.request_id, "req-abc");
+    }
+
+    #[test]
+    fn test_create_txt_record_payload() {
+        let request = CreateTXTRecord::new("dnspod.cn", "v=spf1 -all")
+            .with_sub_domain("@")
+            .with_ttl(600);
+
+        let payload = request.payload();
+        assert_eq!(payload["RecordType"], json!("TXT"));
+        assert_eq!(payload["Value"], json!("v=spf1 -all"));
+        assert_eq!(payload["SubDomain"], json!("@"));
+    }
+
+    #[test]
+    fn test_endpoint_implementation() {
+        let request = CreateRecord::new("test.com", RecordData::TXT("hi".into()));
+        assert_eq!(request.service().as_ref(), "dnspod");
+        assert_eq!(request.action().as_ref(), "CreateRecord");
+        assert_eq!(request.version().as_ref(), "2021-03-23");
+        assert!(request.region().is_none());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::core::Endpoint;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+/// DNSKEY 记录信息
+#[derive(Debug, Deserialize)]
+pub struct DnsKey {
+    /// 标志
+    #[serde(rename = "Flags")]
+    pub flags: i64,
+
+    /// 协议
+    #[serde(rename = "Protocol")]
+    pub protocol: String,
+
+    /// 加密算法
+    #[serde(rename = "Algorithm")]
+    pub algorithm: String,
+
+    /// 公钥
+    #[serde(rename = "PublicKey")]
+    pub public_key: String,
+}
+
+/// DS 记录信息
+#[derive(Debug, Deserialize)]
+pub struct DsRecord {
+    /// 密钥标签
+    #[serde(rename = "KeyTag")]
+    pub key_tag: i64,
+
+    /// 加密算法
+    #[serde(rename = "Algorithm")]
+    pub algorithm: String,
+
+    /// 摘要类型
+    #[serde(rename = "DigestType")]
+    pub digest_type: String,
+
+    /// 摘要信息
+    #[serde(rename = "Digest")]
+    pub digest: String,
+}
+
+/// 查询 DNSSEC 信息结果
+#[derive(Debug, Deserialize)]
+pub struct DescribeDomainDnssecResult {
+    /// DNSSEC 状态，开启：enabled，关闭：disabled
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    /// DNSKEY 记录信息，未开启时为空
+    #[serde(rename = "Dnskey")]
+    pub dnskey: Option<DnsKey>,
+
+    /// DS 记录信息，未开启时为空
+    #[serde(rename = "Ds")]
+    pub ds: Option<DsRecord>,
+
+    /// 唯一请求ID
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+/// 查询 DNSSEC 信息响应
+#[derive(Debug, Deserialize)]
+pub struct DescribeDomainDnssecResponse {
+    #[serde(rename = "Response")]
+    pub response: DescribeDomainDnssecResult,
+}
+
+/// 请求参数结构体 - 查询域名 DNSSEC 信息
+pub struct DescribeDomainDnssec<'a> {
+    /// 域名
+    pub domain: &'a str,
+
+    /// 域名ID。参数 DomainId 优先级比参数 Domain 高
+    pub domain_id: Option<u64>,
+}
+
+impl<'a> DescribeDomainDnssec<'a> {
+    /// 创建新的请求实例
+    pub fn new(domain: &'a str) -> Self {
+        Self {
+            domain,
+            domain_id: None,
+        }
+    }
+
+    /// 设置域名ID
+    pub fn with_domain_id(mut self, domain_id: u64) -> Self {
+        self.domain_id = Some(domain_id);
+        self
+    }
+}
+
+impl<'a> Endpoint for DescribeDomainDnssec<'a> {
+    type Output = DescribeDomainDnssecResponse;
+
+    fn service(&self) -> Cow<'static, str> {
+        Cow::Borrowed("dnspod")
+    }
+
+    fn action(&self) -> Cow<'static, str> {
+        Cow::Borrowed("DescribeDomainDNSSEC")
+    }
+
+    fn version(&self) -> Cow<'static, str> {
+        Cow::Borrowed("2021-03-23")
+    }
+
+    fn region(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn payload(&self) -> Value {
+        let mut payload = json!({
+            "Domain": self.domain,
+        });
+
+        if let Some(domain_id) = self.domain_id {
+            payload["DomainId"] = json!(domain_id);
+        }
+
+        payload
+    }
+}
+
+/// 修改 DNSSEC 状态结果
+#[derive(Debug, Deserialize)]
+pub struct ModifyDomainDnssecResult {
+    /// 唯一请求ID
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+/// 修改 DNSSEC 状态响应
+#[derive(Debug, Deserialize)]
+pub struct ModifyDomainDnssecResponse {
+    #[serde(rename = "Response")]
+    pub response: ModifyDomainDnssecResult,
+}
+
+/// 请求参数结构体 - 开启/关闭域名 DNSSEC
+pub struct ModifyDomainDnssec<'a> {
+    /// 域名
+    pub domain: &'a str,
+
+    /// DNSSEC 状态，开启：enable，关闭：disable
+    pub status: &'a str,
+
+    /// 域名ID。参数 DomainId 优先级比参数 Domain 高
+    pub domain_id: Option<u64>,
+}
+
+impl<'a> ModifyDomainDnssec<'a> {
+    /// 创建新的请求实例
+    pub fn new(domain: &'a str, status: &'a str) -> Self {
+        Self {
+            domain,
+            status,
+            domain_id: None,
+        }
+    }
+
+    /// 设置域名ID
+    pub fn with_domain_id(mut self, domain_id: u64) -> Self {
+        self.domain_id = Some(domain_id);
+        self
+    }
+}
+
+impl<'a> Endpoint for ModifyDomainDnssec<'a> {
+    type Output = ModifyDomainDnssecResponse;
+
+    fn service(&self) -> Cow<'static, str> {
+        Cow::Borrowed("dnspod")
+    }
+
+    fn action(&self) -> Cow<'static, str> {
+        Cow::Borrowed("ModifyDomainDNSSEC")
+    }
+
+    fn version(&self) -> Cow<'static, str> {
+        Cow::Borrowed("2021-03-23")
+    }
+
+    fn region(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn payload(&self) -> Value {
+        let mut payload = json!({
+            "Domain": self.domain,
+            "Status": self.status,
+        });
+
+        if let Some(domain_id) = self.domain_id {
+            payload["DomainId"] = json!(domain_id);
+        }
+
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modify_dnssec_payload() {
+        let request = ModifyDomainDnssec::new("dnspod.cn", "enable").with_domain_id(81345824);
+        let payload = request.payload();
+        assert_eq!(payload["Domain"], json!("dnspod.cn"));
+        assert_eq!(payload["Status"], json!("enable"));
+        assert_eq!(payload["DomainId"], json!(81345824));
+        assert_eq!(request.action().as_ref(), "ModifyDomainDNSSEC");
+    }
+
+    #[test]
+    fn test_describe_dnssec_endpoint() {
+        let request = DescribeDomainDnssec::new("dnspod.cn");
+        assert_eq!(request.service().as_ref(), "dnspod");
+        assert_eq!(request.action().as_ref(), "DescribeDomainDNSSEC");
+        assert_eq!(request.version().as_ref(), "2021-03-23");
+        assert!(request.region().is_none());
+    }
+
+    #[test]
+    fn test_deserialize_describe_dnssec_enabled() {
+        let json = r#"{
+            "Response": {
+                "Status": "enabled",
+                "Dnskey": {
+                    "Flags": 257,
+                    "Protocol": "3",
+                    "Algorithm": "13",
+                    "PublicKey": "mdsswUyr3D..."
+                },
+                "Ds": {
+                    "KeyTag": 12345,
+                    "Algorithm": "13",
+                    "DigestType": "2",
+                    "Digest": "49FD46E6C4B45C55D4AC..."
+                },
+                "RequestId": "req-dnssec"
+            }
+        }"#;
+
+        let response: DescribeDomainDnssecResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.response.status, "enabled");
+        let dnskey = response.response.dnskey.unwrap();
+        assert_eq!(dnskey.flags, 257);
+        assert_eq!(dnskey.algorithm, "13");
+        let ds = response.response.ds.unwrap();
+        assert_eq!(ds.key_tag, 12345);
+        assert_eq!(ds.digest_type, "2");
+    }
+
+    #[test]
+    fn test_deserialize_describe_dnssec_disabled() {
+        let json = r#"{
+            "Response": {
+                "Status": "disabled",
+                "RequestId": "req-dnssec-off"
+            }
+        }"#;
+
+        let response: DescribeDomainDnssecResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.response.status, "disabled");
+        assert!(response.response.dnskey.is_none());
+        assert!(response.response.ds.is_none());
+    }
+}
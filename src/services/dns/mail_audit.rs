@@ -0,0 +1,313 @@
+use super::create::CreateRecord;
+use super::record_data::RecordData;
+use super::record_list::{DomainRecordList, RecordListItem};
+use crate::core::{Client, Result};
+use std::fmt;
+
+/// DMARC 策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmarcPolicy {
+    /// 不做处理，仅收集报告
+    None,
+    /// 隔离可疑邮件
+    Quarantine,
+    /// 拒收未通过校验的邮件
+    Reject,
+}
+
+impl fmt::Display for DmarcPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            DmarcPolicy::None => "none",
+            DmarcPolicy::Quarantine => "quarantine",
+            DmarcPolicy::Reject => "reject",
+        };
+        f.write_str(s)
+    }
+}
+
+/// 邮件认证配置
+///
+/// 用于生成某个域名推荐的邮件相关解析记录（MX、SPF、DKIM、DMARC）。
+pub struct MailConfig {
+    /// MX 服务器主机名
+    pub mx_host: String,
+
+    /// MX 优先级
+    pub mx_preference: u16,
+
+    /// DKIM 选择器名称，如 `default`
+    pub dkim_selector: String,
+
+    /// DKIM 公钥（记录值中 `p=` 之后的内容）
+    pub dkim_public_key: String,
+
+    /// DMARC 策略
+    pub dmarc_policy: DmarcPolicy,
+}
+
+/// 一条推荐的解析记录
+#[derive(Debug, Clone)]
+pub struct RecommendedRecord {
+    /// 主机记录，如 `@`、`_dmarc`、`<selector>._domainkey`
+    pub sub_domain: String,
+
+    /// 记录数据
+    pub data: RecordData,
+}
+
+/// 某条推荐记录与当前区域的比对结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditStatus {
+    /// 区域中缺少该记录
+    Missing,
+    /// 记录存在但取值与推荐值不一致
+    Divergent {
+        /// 区域中当前的取值
+        current: Vec<String>,
+    },
+    /// 记录已存在且与推荐值一致
+    Correct,
+}
+
+/// 单条推荐记录的审计结论
+#[derive(Debug, Clone)]
+pub struct AuditFinding {
+    /// 推荐的记录
+    pub recommended: RecommendedRecord,
+
+    /// 比对状态
+    pub status: AuditStatus,
+}
+
+/// 邮件 DNS 审计
+pub struct MailAudit<'a> {
+    domain: &'a str,
+    config: MailConfig,
+}
+
+impl<'a> MailAudit<'a> {
+    /// 创建新的审计实例
+    pub fn new(domain: &'a str, config: MailConfig) -> Self {
+        Self { domain, config }
+    }
+
+    /// 生成该域名推荐的邮件相关解析记录
+    pub fn recommended_records(&self) -> Vec<RecommendedRecord> {
+        vec![
+            RecommendedRecord {
+                sub_domain: "@".to_string(),
+                data: RecordData::MX {
+                    preference: self.config.mx_preference,
+                    exchange: self.config.mx_host.clone(),
+                },
+            },
+            RecommendedRecord {
+                sub_domain: "@".to_string(),
+                data: RecordData::TXT("v=spf1 mx ~all".to_string()),
+            },
+            RecommendedRecord {
+                sub_domain: format!("{}._domainkey", self.config.dkim_selector),
+                data: RecordData::TXT(format!(
+                    "v=DKIM1; k=rsa; p={}",
+                    self.config.dkim_public_key
+                )),
+            },
+            RecommendedRecord {
+                sub_domain: "_dmarc".to_string(),
+                data: RecordData::TXT(format!("v=DMARC1; p={}", self.config.dmarc_policy)),
+            },
+        ]
+    }
+
+    /// 将推荐记录与已获取的区域记录逐条比对
+    pub fn audit(&self, records: &[RecordListItem]) -> Vec<AuditFinding> {
+        self.recommended_records()
+            .into_iter()
+            .map(|recommended| {
+                let record_type = recommended.data.record_type();
+                // TXT 记录的主机名（如 `@`）下可能并存多条互不相关的文本记录（域名验证
+                // token 等），因此按推荐值的标识前缀（v=spf1 / v=DKIM1 / v=DMARC1）
+                // 进一步缩小匹配范围，避免把无关 TXT 误判为分歧。
+                let prefix = txt_identifier(&recommended.data.value());
+                let matching: Vec<&RecordListItem> = records
+                    .iter()
+                    .filter(|r| r.name == recommended.sub_domain && r.record_type == record_type)
+                    .filter(|r| match prefix {
+                        Some(p) => r.value.starts_with(p),
+                        None => true,
+                    })
+                    .collect();
+
+                let status = if matching.is_empty() {
+                    AuditStatus::Missing
+                } else if matching.iter().any(|r| is_correct(r, &recommended.data)) {
+                    AuditStatus::Correct
+                } else {
+                    AuditStatus::Divergent {
+                        current: matching.iter().map(|r| r.value.clone()).collect(),
+                    }
+                };
+
+                AuditFinding {
+                    recommended,
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    /// 拉取当前区域并完成审计
+    pub async fn audit_zone(&self, client: &Client) -> Result<Vec<AuditFinding>> {
+        let records = DomainRecordList::new(self.domain).fetch_all(client).await?;
+        Ok(self.audit(&records))
+    }
+
+    /// 根据审计结论生成使区域达标所需的新增记录调用
+    ///
+    /// 仅为缺失或取值不一致的记录生成 [`CreateRecord`]，与既有的 TXT 添加路径一致。
+    pub fn fix_calls<'f>(&'f self, findings: &'f [AuditFinding]) -> Vec<CreateRecord<'f>> {
+        findings
+            .iter()
+            .filter(|f| !matches!(f.status, AuditStatus::Correct))
+            .map(|f| {
+                CreateRecord::new(self.domain, f.recommended.data.clone())
+                    .with_sub_domain(&f.recommended.sub_domain)
+            })
+            .collect()
+    }
+}
+
+/// 返回 TXT 推荐值的标识前缀，用于在同名多条 TXT 中定位对应记录
+fn txt_identifier(value: &str) -> Option<&'static str> {
+    for prefix in ["v=spf1", "v=DKIM1", "v=DMARC1"] {
+        if value.starts_with(prefix) {
+            return Some(prefix);
+        }
+    }
+    None
+}
+
+/// 判断区域中的一条记录是否与推荐值完全一致
+///
+/// MX 记录除取值（交换主机）外还需比较优先级，否则主机相同但优先级不同会被误判为一致。
+fn is_correct(record: &RecordListItem, data: &RecordData) -> bool {
+    if record.value != data.value() {
+        return false;
+    }
+    match data.mx() {
+        Some(preference) => record.mx == Some(u32::from(preference)),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::record_list::DomainRecordListResponse;
+    use super::*;
+
+    fn config() -> MailConfig {
+        MailConfig {
+            mx_host: "mx.example.com".to_string(),
+            mx_preference: 10,
+            dkim_selector: "default".to_string(),
+            dkim_public_key: "MIGfMA0G".to_string(),
+            dmarc_policy: DmarcPolicy::Quarantine,
+        }
+    }
+
+    #[test]
+    fn test_recommended_records() {
+        let audit = MailAudit::new("example.com", config());
+        let records = audit.recommended_records();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].sub_domain, "@");
+        assert_eq!(records[0].data.record_type(), "MX");
+        assert_eq!(records[2].sub_domain, "default._domainkey");
+        assert_eq!(records[3].sub_domain, "_dmarc");
+        assert_eq!(records[3].data.value(), "v=DMARC1; p=quarantine");
+    }
+
+    #[test]
+    fn test_audit_detects_missing_divergent_correct() {
+        let json = r#"{
+            "Response": {
+                "RecordCountInfo": { "SubdomainCount": 2, "ListCount": 2, "TotalCount": 2 },
+                "RecordList": [
+                    {
+                        "RecordId": 1, "Value": "mx.example.com", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:09", "Name": "@", "Line": "默认",
+                        "LineId": "0", "Type": "MX", "MX": 10, "TTL": 600, "DefaultNS": false
+                    },
+                    {
+                        "RecordId": 2, "Value": "v=spf1 -all", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:10", "Name": "@", "Line": "默认",
+                        "LineId": "0", "Type": "TXT", "TTL": 600, "DefaultNS": false
+                    }
+                ],
+                "RequestId": "req-audit"
+            }
+        }"#;
+
+        let response: DomainRecordListResponse = serde_json::from_str(json).unwrap();
+        let audit = MailAudit::new("example.com", config());
+        let findings = audit.audit(&response.response.record_list);
+
+        // MX 一致
+        assert_eq!(findings[0].status, AuditStatus::Correct);
+        // SPF 取值不同
+        assert!(matches!(findings[1].status, AuditStatus::Divergent { .. }));
+        // DKIM 与 DMARC 缺失
+        assert_eq!(findings[2].status, AuditStatus::Missing);
+        assert_eq!(findings[3].status, AuditStatus::Missing);
+
+        let fixes = audit.fix_calls(&findings);
+        assert_eq!(fixes.len(), 3);
+    }
+
+    #[test]
+    fn test_mx_preference_mismatch_is_divergent() {
+        // 主机一致但优先级不同，应判定为分歧而非一致
+        let json = r#"{
+            "Response": {
+                "RecordCountInfo": { "SubdomainCount": 1, "ListCount": 1, "TotalCount": 1 },
+                "RecordList": [
+                    {
+                        "RecordId": 1, "Value": "mx.example.com", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:09", "Name": "@", "Line": "默认",
+                        "LineId": "0", "Type": "MX", "MX": 20, "TTL": 600, "DefaultNS": false
+                    }
+                ],
+                "RequestId": "req-audit"
+            }
+        }"#;
+
+        let response: DomainRecordListResponse = serde_json::from_str(json).unwrap();
+        let audit = MailAudit::new("example.com", config());
+        let findings = audit.audit(&response.response.record_list);
+        assert!(matches!(findings[0].status, AuditStatus::Divergent { .. }));
+    }
+
+    #[test]
+    fn test_unrelated_apex_txt_does_not_mask_spf() {
+        // 仅存在一条域名验证 TXT 时，SPF 应判为缺失而非把该 token 吞进分歧
+        let json = r#"{
+            "Response": {
+                "RecordCountInfo": { "SubdomainCount": 1, "ListCount": 1, "TotalCount": 1 },
+                "RecordList": [
+                    {
+                        "RecordId": 1, "Value": "google-site-verification=abc", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:10", "Name": "@", "Line": "默认",
+                        "LineId": "0", "Type": "TXT", "TTL": 600, "DefaultNS": false
+                    }
+                ],
+                "RequestId": "req-audit"
+            }
+        }"#;
+
+        let response: DomainRecordListResponse = serde_json::from_str(json).unwrap();
+        let audit = MailAudit::new("example.com", config());
+        let findings = audit.audit(&response.response.record_list);
+        assert_eq!(findings[1].status, AuditStatus::Missing);
+    }
+}
@@ -1,9 +1,22 @@
 mod create;
-mod delete;
+mod dnssec;
+mod mail_audit;
 mod modify;
+mod record_data;
 mod record_list;
+mod record_set;
+mod weighted;
 
-pub use create::CreateTXTRecord;
-pub use delete::DeleteRecord;
-pub use modify::ModifyTXTRecord;
+pub use create::{CreateRecord, CreateRecordResponse, CreateRecordResult, CreateTXTRecord};
+pub use dnssec::{
+    DescribeDomainDnssec, DescribeDomainDnssecResponse, DescribeDomainDnssecResult, DnsKey,
+    DsRecord, ModifyDomainDnssec, ModifyDomainDnssecResponse, ModifyDomainDnssecResult,
+};
+pub use mail_audit::{
+    AuditFinding, AuditStatus, DmarcPolicy, MailAudit, MailConfig, RecommendedRecord,
+};
+pub use modify::{ModifyRecord, ModifyRecordResponse, ModifyRecordResult, ModifyTXTRecord};
+pub use record_data::RecordData;
 pub use record_list::{DomainRecordList, DomainRecordListResult, RecordCountInfo, RecordListItem};
+pub use record_set::{RecordSet, RecordSetValue};
+pub use weighted::{WeightedRecord, WeightedSelector};
@@ -0,0 +1,154 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// 带类型的解析记录数据
+///
+/// DNSPod 接口里记录值 `Value` 与记录类型 `RecordType` 是两个独立的字符串字段，
+/// 调用方很容易填入与类型不匹配的值（例如给 `A` 记录写了一个域名）。借鉴
+/// hickory-dns 以 `RData` 派生记录类型的做法，这里用一个枚举同时承载类型与取值，
+/// 由枚举变体决定 `RecordType`，从而在编译期杜绝类型/取值不一致。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordData {
+    /// IPv4 地址记录
+    A(Ipv4Addr),
+
+    /// IPv6 地址记录
+    AAAA(Ipv6Addr),
+
+    /// 别名记录，指向另一个域名
+    CNAME(String),
+
+    /// 邮件交换记录
+    MX {
+        /// 优先级，数值越小优先级越高
+        preference: u16,
+        /// 邮件服务器域名
+        exchange: String,
+    },
+
+    /// 文本记录
+    TXT(String),
+
+    /// 域名服务器记录
+    NS(String),
+
+    /// 证书颁发机构授权记录
+    CAA {
+        /// 标志位
+        flags: u8,
+        /// 属性标签，如 issue、issuewild、iodef
+        tag: String,
+        /// 属性值
+        value: String,
+    },
+
+    /// SPF 记录
+    SPF(String),
+}
+
+impl RecordData {
+    /// 返回 DNSPod 接口使用的记录类型字符串
+    pub fn record_type(&self) -> &'static str {
+        match self {
+            RecordData::A(_) => "A",
+            RecordData::AAAA(_) => "AAAA",
+            RecordData::CNAME(_) => "CNAME",
+            RecordData::MX { .. } => "MX",
+            RecordData::TXT(_) => "TXT",
+            RecordData::NS(_) => "NS",
+            RecordData::CAA { .. } => "CAA",
+            RecordData::SPF(_) => "SPF",
+        }
+    }
+
+    /// 返回填入 `Value` 字段的记录值
+    pub fn value(&self) -> String {
+        match self {
+            RecordData::A(addr) => addr.to_string(),
+            RecordData::AAAA(addr) => addr.to_string(),
+            RecordData::CNAME(name)
+            | RecordData::TXT(name)
+            | RecordData::NS(name)
+            | RecordData::SPF(name) => name.clone(),
+            RecordData::MX { exchange, .. } => exchange.clone(),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+        }
+    }
+
+    /// 返回 `MX` 字段的优先级，仅 MX 记录需要
+    pub fn mx(&self) -> Option<u16> {
+        match self {
+            RecordData::MX { preference, .. } => Some(*preference),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_type_derivation() {
+        assert_eq!(RecordData::A(Ipv4Addr::new(1, 1, 1, 1)).record_type(), "A");
+        assert_eq!(
+            RecordData::AAAA(Ipv6Addr::LOCALHOST).record_type(),
+            "AAAA"
+        );
+        assert_eq!(RecordData::CNAME("a.com".into()).record_type(), "CNAME");
+        assert_eq!(
+            RecordData::MX {
+                preference: 10,
+                exchange: "mail.a.com".into(),
+            }
+            .record_type(),
+            "MX"
+        );
+        assert_eq!(RecordData::TXT("hello".into()).record_type(), "TXT");
+        assert_eq!(RecordData::NS("ns1.a.com".into()).record_type(), "NS");
+        assert_eq!(
+            RecordData::CAA {
+                flags: 0,
+                tag: "issue".into(),
+                value: "letsencrypt.org".into(),
+            }
+            .record_type(),
+            "CAA"
+        );
+        assert_eq!(RecordData::SPF("v=spf1 -all".into()).record_type(), "SPF");
+    }
+
+    #[test]
+    fn test_value_rendering() {
+        assert_eq!(RecordData::A(Ipv4Addr::new(1, 2, 3, 4)).value(), "1.2.3.4");
+        assert_eq!(
+            RecordData::MX {
+                preference: 10,
+                exchange: "mail.a.com".into(),
+            }
+            .value(),
+            "mail.a.com"
+        );
+        assert_eq!(
+            RecordData::CAA {
+                flags: 0,
+                tag: "issue".into(),
+                value: "letsencrypt.org".into(),
+            }
+            .value(),
+            "0 issue \"letsencrypt.org\""
+        );
+    }
+
+    #[test]
+    fn test_mx_preference() {
+        assert_eq!(
+            RecordData::MX {
+                preference: 20,
+                exchange: "mail.a.com".into(),
+            }
+            .mx(),
+            Some(20)
+        );
+        assert_eq!(RecordData::A(Ipv4Addr::new(1, 1, 1, 1)).mx(), None);
+    }
+}
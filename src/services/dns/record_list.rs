@@ -1,4 +1,4 @@
-use crate::core::Endpoint;
+use crate::core::{Client, Endpoint, Result};
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::borrow::Cow;
@@ -225,6 +225,41 @@ impl<'a> DomainRecordList<'a> {
         self.limit = Some(limit);
         self
     }
+
+    /// 拉取当前过滤条件下的全部解析记录
+    ///
+    /// 单次 `DescribeRecordList` 的 `Limit` 最大为 3000，记录较多的域名需要翻页。
+    /// 本方法从 `offset = 0` 开始按最大页长反复请求，每页之后按返回的 `ListCount`
+    /// 推进 `offset`，直到累计记录数达到 `TotalCount` 为止，并保留 `subdomain`、
+    /// `record_type`、`keyword`、排序等过滤字段，最终返回扁平化的记录列表。
+    pub async fn fetch_all(&self, client: &Client) -> Result<Vec<RecordListItem>> {
+        /// 单次请求允许的最大记录数
+        const PAGE_LIMIT: u32 = 3000;
+
+        let mut records = Vec::new();
+        let mut offset = 0u32;
+
+        loop {
+            let page = DomainRecordList {
+                offset: Some(offset),
+                limit: Some(PAGE_LIMIT),
+                ..*self
+            };
+
+            let result = client.send(&page).await?.response;
+            let total_count = result.record_count_info.total_count;
+            let list_count = result.record_count_info.list_count;
+
+            records.extend(result.record_list);
+            offset += list_count;
+
+            if list_count == 0 || records.len() as u32 >= total_count {
+                break;
+            }
+        }
+
+        Ok(records)
+    }
 }
 
 impl<'a> Endpoint for DomainRecordList<'a> {
@@ -413,7 +448,7 @@ mod tests {
     fn test_endpoint_implementation() {
         let request = DomainRecordList::new("test.com");
         assert_eq!(request.service().as_ref(), "dnspod");
-        assert_eq!(request.action().as_ref(), "DomainRecordList");
+        assert_eq!(request.action().as_ref(), "DescribeRecordList");
         assert_eq!(request.version().as_ref(), "2021-03-23");
         assert!(request.region().is_none());
     }
@@ -0,0 +1,149 @@
+use super::record_list::{DomainRecordListResult, RecordListItem};
+use std::collections::BTreeMap;
+
+/// 记录集合中的单个取值
+///
+/// 同一个 [`RecordSet`] 下可以有多个取值（多个 A 记录、多台 MX 服务器等），
+/// 每个取值保留自己的 `RecordId`、权重与状态，用于加权/轮询选择。
+#[derive(Debug, Clone)]
+pub struct RecordSetValue {
+    /// 记录ID
+    pub record_id: u64,
+
+    /// 记录值
+    pub value: String,
+
+    /// 记录权重，用于负载均衡记录
+    pub weight: Option<u32>,
+
+    /// 记录状态，启用：ENABLE，暂停：DISABLE
+    pub status: String,
+
+    /// 记录监控状态
+    pub monitor_status: Option<String>,
+}
+
+/// 记录集合
+///
+/// 借鉴 trust-dns-proto 的 `RecordSet`：共享同一主机名 + 记录类型 + 线路的记录
+/// 归为一组，携带单一 TTL 和一组取值。这与 DNS 实际解析一个标签的方式（同名同类型
+/// 下多个取值）一致，也是轮询/加权选择的天然输入。
+#[derive(Debug, Clone)]
+pub struct RecordSet {
+    /// 主机名
+    pub name: String,
+
+    /// 记录类型
+    pub record_type: String,
+
+    /// 记录线路
+    pub line: String,
+
+    /// 线路ID
+    pub line_id: String,
+
+    /// 记录缓存时间，单位：秒
+    pub ttl: u32,
+
+    /// 集合中的取值列表
+    pub values: Vec<RecordSetValue>,
+}
+
+impl DomainRecordListResult {
+    /// 将扁平的记录列表按 `(name, record_type, line_id)` 折叠为记录集合
+    ///
+    /// 返回的集合按键有序，每个集合的 TTL 取该组中第一条记录的 TTL。
+    pub fn into_record_sets(self) -> Vec<RecordSet> {
+        let mut sets: BTreeMap<(String, String, String), RecordSet> = BTreeMap::new();
+
+        for record in self.record_list {
+            let RecordListItem {
+                record_id,
+                value,
+                status,
+                name,
+                line,
+                line_id,
+                record_type,
+                weight,
+                monitor_status,
+                ttl,
+                ..
+            } = record;
+
+            let key = (name.clone(), record_type.clone(), line_id.clone());
+            let set = sets.entry(key).or_insert_with(|| RecordSet {
+                name,
+                record_type,
+                line,
+                line_id,
+                ttl,
+                values: Vec::new(),
+            });
+
+            set.values.push(RecordSetValue {
+                record_id,
+                value,
+                weight,
+                status,
+                monitor_status,
+            });
+        }
+
+        sets.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::record_list::DomainRecordListResponse;
+
+    #[test]
+    fn test_into_record_sets_groups_by_name_type_line() {
+        let json = r#"{
+            "Response": {
+                "RecordCountInfo": { "SubdomainCount": 1, "ListCount": 3, "TotalCount": 3 },
+                "RecordList": [
+                    {
+                        "RecordId": 1, "Value": "1.1.1.1", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:09", "Name": "www", "Line": "默认",
+                        "LineId": "0", "Type": "A", "Weight": 10, "TTL": 600, "DefaultNS": false
+                    },
+                    {
+                        "RecordId": 2, "Value": "2.2.2.2", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:10", "Name": "www", "Line": "默认",
+                        "LineId": "0", "Type": "A", "Weight": 20, "TTL": 600, "DefaultNS": false
+                    },
+                    {
+                        "RecordId": 3, "Value": "c.example.com", "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:11", "Name": "cdn", "Line": "默认",
+                        "LineId": "0", "Type": "CNAME", "TTL": 300, "DefaultNS": false
+                    }
+                ],
+                "RequestId": "req-set"
+            }
+        }"#;
+
+        let response: DomainRecordListResponse = serde_json::from_str(json).unwrap();
+        let sets = response.response.into_record_sets();
+
+        assert_eq!(sets.len(), 2);
+
+        let www = sets
+            .iter()
+            .find(|s| s.name == "www" && s.record_type == "A")
+            .unwrap();
+        assert_eq!(www.line_id, "0");
+        assert_eq!(www.ttl, 600);
+        assert_eq!(www.values.len(), 2);
+        assert_eq!(www.values[0].record_id, 1);
+        assert_eq!(www.values[1].weight, Some(20));
+
+        let cdn = sets
+            .iter()
+            .find(|s| s.name == "cdn")
+            .unwrap();
+        assert_eq!(cdn.record_type, "CNAME");
+        assert_eq!(cdn.values.len(), 1);
+    }
+}
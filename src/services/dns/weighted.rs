@@ -0,0 +1,184 @@
+use super::record_list::RecordListItem;
+use super::record_set::RecordSetValue;
+
+/// 参与加权选择的记录需要提供的信息
+///
+/// [`RecordListItem`] 与 [`RecordSetValue`] 都实现了本 trait，因此同一个选择器
+/// 既能作用于过滤后的扁平记录列表，也能作用于 [`RecordSet`](super::RecordSet) 的取值。
+pub trait WeightedRecord {
+    /// 配置的权重，`None` 表示未设置
+    fn weight(&self) -> Option<u32>;
+
+    /// 记录状态，启用：ENABLE，暂停：DISABLE
+    fn status(&self) -> &str;
+
+    /// 记录监控状态，宕机为 DOWN
+    fn monitor_status(&self) -> Option<&str>;
+
+    /// 记录是否可参与选择：已启用且监控未宕机
+    fn is_eligible(&self) -> bool {
+        self.status() == "ENABLE" && self.monitor_status() != Some("DOWN")
+    }
+}
+
+impl WeightedRecord for RecordListItem {
+    fn weight(&self) -> Option<u32> {
+        self.weight
+    }
+
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn monitor_status(&self) -> Option<&str> {
+        self.monitor_status.as_deref()
+    }
+}
+
+impl WeightedRecord for RecordSetValue {
+    fn weight(&self) -> Option<u32> {
+        self.weight
+    }
+
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn monitor_status(&self) -> Option<&str> {
+        self.monitor_status.as_deref()
+    }
+}
+
+/// 未设置权重时使用的默认权重
+const DEFAULT_WEIGHT: i64 = 1;
+
+struct Entry<'a, T> {
+    item: &'a T,
+    effective_weight: i64,
+    current_weight: i64,
+}
+
+/// 平滑加权轮询选择器（nginx 算法）
+///
+/// 为每条可用记录维护一个 `current_weight`，每次选择时先给每条记录的
+/// `current_weight` 加上其配置权重，挑出 `current_weight` 最大的记录，再从被选中的
+/// 记录上减去所有记录的权重之和。未设置权重的记录按权重 1 处理，仅 `Status == ENABLE`
+/// 且监控未宕机的记录参与选择，以此在客户端实现 DNS 负载均衡。
+pub struct WeightedSelector<'a, T> {
+    entries: Vec<Entry<'a, T>>,
+    total_weight: i64,
+}
+
+impl<'a, T: WeightedRecord> WeightedSelector<'a, T> {
+    /// 从一组记录构造选择器，自动过滤掉不可用的记录
+    pub fn new(items: &'a [T]) -> Self {
+        let entries: Vec<Entry<'a, T>> = items
+            .iter()
+            .filter(|item| item.is_eligible())
+            .map(|item| Entry {
+                item,
+                effective_weight: item.weight().map(i64::from).unwrap_or(DEFAULT_WEIGHT),
+                current_weight: 0,
+            })
+            .collect();
+
+        let total_weight = entries.iter().map(|e| e.effective_weight).sum();
+
+        Self {
+            entries,
+            total_weight,
+        }
+    }
+
+    /// 当前可参与选择的记录数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否没有任何可用记录
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 按平滑加权轮询选出下一条记录
+    pub fn select(&mut self) -> Option<&'a T> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let mut best = 0usize;
+        for i in 0..self.entries.len() {
+            self.entries[i].current_weight += self.entries[i].effective_weight;
+            if self.entries[i].current_weight > self.entries[best].current_weight {
+                best = i;
+            }
+        }
+
+        self.entries[best].current_weight -= self.total_weight;
+        Some(self.entries[best].item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(record_id: u64, weight: Option<u32>, status: &str) -> RecordSetValue {
+        RecordSetValue {
+            record_id,
+            value: format!("10.0.0.{record_id}"),
+            weight,
+            status: status.to_string(),
+            monitor_status: None,
+        }
+    }
+
+    #[test]
+    fn test_smooth_weighted_sequence() {
+        // 经典 nginx 例子：权重 {a:5, b:1, c:1}，7 次应得 a a b a c a a
+        let records = vec![
+            value(1, Some(5), "ENABLE"),
+            value(2, Some(1), "ENABLE"),
+            value(3, Some(1), "ENABLE"),
+        ];
+        let mut selector = WeightedSelector::new(&records);
+
+        let picked: Vec<u64> = (0..7)
+            .map(|_| selector.select().unwrap().record_id)
+            .collect();
+        assert_eq!(picked, vec![1, 1, 2, 1, 3, 1, 1]);
+    }
+
+    #[test]
+    fn test_none_weight_defaults_to_one() {
+        let records = vec![
+            value(1, None, "ENABLE"),
+            value(2, None, "ENABLE"),
+        ];
+        let mut selector = WeightedSelector::new(&records);
+
+        let picked: Vec<u64> = (0..4)
+            .map(|_| selector.select().unwrap().record_id)
+            .collect();
+        assert_eq!(picked, vec![1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_disabled_and_down_are_excluded() {
+        let mut down = value(2, Some(10), "ENABLE");
+        down.monitor_status = Some("DOWN".to_string());
+        let records = vec![value(1, Some(1), "ENABLE"), down, value(3, Some(1), "DISABLE")];
+
+        let mut selector = WeightedSelector::new(&records);
+        assert_eq!(selector.len(), 1);
+        assert_eq!(selector.select().unwrap().record_id, 1);
+    }
+
+    #[test]
+    fn test_empty_selector() {
+        let records: Vec<RecordSetValue> = vec![value(1, Some(1), "DISABLE")];
+        let mut selector = WeightedSelector::new(&records);
+        assert!(selector.is_empty());
+        assert!(selector.select().is_none());
+    }
+}